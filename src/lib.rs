@@ -64,6 +64,9 @@
 
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
 pub extern crate num_traits;
 pub extern crate typenum;
 
@@ -71,7 +74,7 @@ pub extern crate typenum;
 pub mod aliases;
 
 use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
-use core::fmt::{Debug, Error, Formatter};
+use core::fmt::{Debug, Display, Error, Formatter};
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
@@ -79,7 +82,11 @@ use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize};
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use num_traits::float::FloatCore;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One,
+    SaturatingAdd, SaturatingSub, Signed, ToPrimitive, Zero,
+};
 use typenum::consts::Z0;
 use typenum::marker_traits::{Bit, Integer, Unsigned};
 use typenum::operator_aliases::{AbsVal, Diff, Le, Sum};
@@ -120,6 +127,21 @@ pub struct Fix<Bits, Base, Exp> {
     marker: PhantomData<(Base, Exp)>,
 }
 
+/// Rounding mode for [`Fix::convert_round`], used when scaling down loses precision.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Round toward zero, discarding the remainder. Equivalent to [`Fix::convert`].
+    Truncate,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round to the nearest representable value, with ties rounding away from zero.
+    HalfUp,
+    /// Round to the nearest representable value, with ties rounding to the nearest even value.
+    HalfToEven,
+}
+
 impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
     /// Creates a number.
     ///
@@ -137,7 +159,7 @@ impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
         }
     }
 
-    /// Converts to another _Exp_.
+    /// Converts to another _Exp_, truncating toward zero when scaling down.
     ///
     /// # Examples
     ///
@@ -148,9 +170,59 @@ impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
     /// assert_eq!(kilo, milli.convert());
     /// assert_eq!(milli, kilo.convert());
     /// ```
+    ///
+    /// Converting to a coarser scale drops the fractional part rather than rounding it:
+    ///
+    /// ```
+    /// use fix::aliases::si::{Milli, Unit};
+    /// assert_eq!(Unit::new(1), Milli::new(1999).convert());
+    /// ```
     pub fn convert<ToExp>(self) -> Fix<Bits, Base, ToExp>
     where
-        Bits: FromUnsigned + Pow + Mul<Output = Bits> + Div<Output = Bits>,
+        Bits: FromUnsigned
+            + Pow
+            + Add<Output = Bits>
+            + Mul<Output = Bits>
+            + Div<Output = Bits>
+            + Rem<Output = Bits>
+            + Sub<Output = Bits>
+            + PartialOrd
+            + Default
+            + Clone,
+        Base: Unsigned,
+        Exp: Sub<ToExp>,
+        Diff<Exp, ToExp>: Abs + IsLess<Z0>,
+        AbsVal<Diff<Exp, ToExp>>: Integer,
+    {
+        self.convert_round(RoundingMode::Truncate)
+    }
+
+    /// Converts to another _Exp_, applying `mode` to round the result when scaling down.
+    ///
+    /// Scaling up is always exact, so `mode` only has an effect when moving to a coarser `Exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::si::{Milli, Unit};
+    /// use fix::RoundingMode;
+    /// let milli = Milli::new(1_999);
+    /// assert_eq!(Unit::new(1), milli.convert_round(RoundingMode::Truncate));
+    /// assert_eq!(Unit::new(2), milli.convert_round(RoundingMode::Ceil));
+    /// assert_eq!(Unit::new(2), milli.convert_round(RoundingMode::HalfUp));
+    /// ```
+    pub fn convert_round<ToExp>(self, mode: RoundingMode) -> Fix<Bits, Base, ToExp>
+    where
+        Bits: FromUnsigned
+            + Pow
+            + Add<Output = Bits>
+            + Mul<Output = Bits>
+            + Div<Output = Bits>
+            + Rem<Output = Bits>
+            + Sub<Output = Bits>
+            + PartialOrd
+            + Default
+            + Clone,
         Base: Unsigned,
         Exp: Sub<ToExp>,
         Diff<Exp, ToExp>: Abs + IsLess<Z0>,
@@ -165,7 +237,59 @@ impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
         let ratio = base.pow(diff as u32);
 
         if inverse {
-            Fix::new(self.bits / ratio)
+            let zero = Bits::default();
+            let one = Bits::from_unsigned::<typenum::consts::U1>();
+            let negative = self.bits < zero;
+            let q = self.bits.clone() / ratio.clone();
+            let r = self.bits % ratio.clone();
+
+            if r == zero {
+                return Fix::new(q);
+            }
+
+            let bumped = if negative {
+                q.clone() - one.clone()
+            } else {
+                q.clone() + one.clone()
+            };
+
+            let q = match mode {
+                RoundingMode::Truncate => q,
+                RoundingMode::Floor => {
+                    if negative {
+                        bumped
+                    } else {
+                        q
+                    }
+                }
+                RoundingMode::Ceil => {
+                    if negative {
+                        q
+                    } else {
+                        bumped
+                    }
+                }
+                RoundingMode::HalfUp => {
+                    let abs_r = if negative { zero.clone() - r } else { r };
+                    if abs_r.clone() + abs_r >= ratio {
+                        bumped
+                    } else {
+                        q
+                    }
+                }
+                RoundingMode::HalfToEven => {
+                    let abs_r = if negative { zero.clone() - r } else { r };
+                    let doubled = abs_r.clone() + abs_r;
+                    let tie_breaks_up = doubled == ratio && q.clone() % (one.clone() + one) != zero;
+                    if doubled > ratio || tie_breaks_up {
+                        bumped
+                    } else {
+                        q
+                    }
+                }
+            };
+
+            Fix::new(q)
         } else {
             Fix::new(self.bits * ratio)
         }
@@ -635,10 +759,589 @@ where
     }
 }
 
+// Saturating arithmetic.
+//
+// Built on top of the checked ops above: on overflow, clamp to `Bits::min_value()` or
+// `Bits::max_value()` depending on which direction the operands overflowed in.
+
+impl<Bits, Base, Exp> SaturatingAdd for Fix<Bits, Base, Exp>
+where
+    Bits: CheckedAdd + Bounded + PartialOrd + Default,
+{
+    fn saturating_add(&self, v: &Self) -> Self {
+        match self.bits.checked_add(&v.bits) {
+            Some(bits) => Self::new(bits),
+            None if self.bits >= Bits::default() => Self::new(Bits::max_value()),
+            None => Self::new(Bits::min_value()),
+        }
+    }
+}
+
+impl<Bits, Base, Exp> SaturatingSub for Fix<Bits, Base, Exp>
+where
+    Bits: CheckedSub + Bounded + PartialOrd + Default,
+{
+    fn saturating_sub(&self, v: &Self) -> Self {
+        match self.bits.checked_sub(&v.bits) {
+            Some(bits) => Self::new(bits),
+            // `self - v` grows toward `+∞` when `v` is negative (subtracting a negative is
+            // adding), and toward `-∞` otherwise.
+            None if v.bits < Bits::default() => Self::new(Bits::max_value()),
+            None => Self::new(Bits::min_value()),
+        }
+    }
+}
+
+/// Adapts `CheckedMulFix`'s computed-`Output` scheme to saturating multiplication.
+pub trait SaturatingMulFix<Rhs> {
+    type Output;
+    fn saturating_mul(&self, v: &Rhs) -> Self::Output;
+}
+
+impl<Bits, Base, LExp, RExp> SaturatingMulFix<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
+where
+    Bits: CheckedMul + Bounded + PartialOrd + Default,
+    LExp: Add<RExp>,
+{
+    type Output = Fix<Bits, Base, Sum<LExp, RExp>>;
+    fn saturating_mul(&self, v: &Fix<Bits, Base, RExp>) -> Self::Output {
+        match self.bits.checked_mul(&v.bits) {
+            Some(bits) => Self::Output::new(bits),
+            None => {
+                let zero = Bits::default();
+                let same_sign = (self.bits < zero) == (v.bits < zero);
+                if same_sign {
+                    Self::Output::new(Bits::max_value())
+                } else {
+                    Self::Output::new(Bits::min_value())
+                }
+            }
+        }
+    }
+}
+
+/// Adapts `CheckedDivFix`'s computed-`Output` scheme to saturating division.
+pub trait SaturatingDivFix<Rhs> {
+    type Output;
+    fn saturating_div(&self, v: &Rhs) -> Self::Output;
+}
+
+impl<Bits, Base, LExp, RExp> SaturatingDivFix<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
+where
+    Bits: CheckedDiv + Bounded + PartialOrd + Default,
+    LExp: Sub<RExp>,
+{
+    type Output = Fix<Bits, Base, Diff<LExp, RExp>>;
+    fn saturating_div(&self, v: &Fix<Bits, Base, RExp>) -> Self::Output {
+        match self.bits.checked_div(&v.bits) {
+            Some(bits) => Self::Output::new(bits),
+            None => {
+                let zero = Bits::default();
+                let same_sign = (self.bits < zero) == (v.bits < zero);
+                if same_sign {
+                    Self::Output::new(Bits::max_value())
+                } else {
+                    Self::Output::new(Bits::min_value())
+                }
+            }
+        }
+    }
+}
+
+// num-traits numeric traits.
+
+impl<Bits, Base, Exp> Zero for Fix<Bits, Base, Exp>
+where
+    Bits: Zero,
+{
+    fn zero() -> Self {
+        Self::new(Bits::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.bits.is_zero()
+    }
+}
+
+// `One`'s multiplicative identity must satisfy `Self: Mul<Self, Output = Self>`, and `Mul`
+// changes `Exp` to `LExp + RExp`. That's only equal to `Self` when `Exp = Z0`, so `One` (and the
+// other traits below that require it through `Num`) is only implemented at that exponent.
+impl<Bits, Base> One for Fix<Bits, Base, Z0>
+where
+    Bits: One,
+{
+    fn one() -> Self {
+        Self::new(Bits::one())
+    }
+}
+
+impl<Bits, Base, Exp> Bounded for Fix<Bits, Base, Exp>
+where
+    Bits: Bounded,
+{
+    fn min_value() -> Self {
+        Self::new(Bits::min_value())
+    }
+
+    fn max_value() -> Self {
+        Self::new(Bits::max_value())
+    }
+}
+
+impl<Bits, Base> Num for Fix<Bits, Base, Z0>
+where
+    Bits: Num,
+{
+    type FromStrRadixErr = Bits::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Bits::from_str_radix(str, radix).map(Self::new)
+    }
+}
+
+impl<Bits, Base> Signed for Fix<Bits, Base, Z0>
+where
+    Bits: Signed,
+{
+    fn abs(&self) -> Self {
+        Self::new(self.bits.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::new(self.bits.abs_sub(&other.bits))
+    }
+
+    fn signum(&self) -> Self {
+        Self::new(self.bits.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.bits.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.bits.is_negative()
+    }
+}
+
+// By-reference arithmetic.
+//
+// These forward to the by-value impls above, cloning the operand(s) first. This lets callers
+// combine `Fix`es without giving up ownership, which matters when `Bits` is expensive to copy
+// (e.g. a bigint).
+
+macro_rules! fixed_ref_unop {
+    ($imp:ident, $method:ident) => {
+        impl<'a, Bits, Base, Exp> $imp for &'a Fix<Bits, Base, Exp>
+        where
+            Fix<Bits, Base, Exp>: $imp<Output = Fix<Bits, Base, Exp>>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, Exp>;
+            fn $method(self) -> Self::Output {
+                $imp::$method(self.clone())
+            }
+        }
+    };
+}
+
+macro_rules! fixed_ref_binop {
+    ($imp:ident, $method:ident) => {
+        impl<'a, Bits, Base, Exp> $imp<Fix<Bits, Base, Exp>> for &'a Fix<Bits, Base, Exp>
+        where
+            Fix<Bits, Base, Exp>: $imp<Output = Fix<Bits, Base, Exp>>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, Exp>;
+            fn $method(self, rhs: Fix<Bits, Base, Exp>) -> Self::Output {
+                $imp::$method(self.clone(), rhs)
+            }
+        }
+
+        impl<'a, Bits, Base, Exp> $imp<&'a Fix<Bits, Base, Exp>> for Fix<Bits, Base, Exp>
+        where
+            Fix<Bits, Base, Exp>: $imp<Output = Fix<Bits, Base, Exp>>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, Exp>;
+            fn $method(self, rhs: &'a Fix<Bits, Base, Exp>) -> Self::Output {
+                $imp::$method(self, rhs.clone())
+            }
+        }
+
+        impl<'a, 'b, Bits, Base, Exp> $imp<&'b Fix<Bits, Base, Exp>> for &'a Fix<Bits, Base, Exp>
+        where
+            Fix<Bits, Base, Exp>: $imp<Output = Fix<Bits, Base, Exp>>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, Exp>;
+            fn $method(self, rhs: &'b Fix<Bits, Base, Exp>) -> Self::Output {
+                $imp::$method(self.clone(), rhs.clone())
+            }
+        }
+    };
+}
+
+// `Mul`/`Div` change the output `Exp` (to `Sum<LExp, RExp>`/`Diff<LExp, RExp>`), so they need
+// their own macro rather than reusing `fixed_ref_binop!`, which assumes `LExp == RExp`.
+macro_rules! fixed_ref_scale_binop {
+    ($imp:ident, $method:ident, $bound:ident, $out:ident) => {
+        impl<'a, Bits, Base, LExp, RExp> $imp<Fix<Bits, Base, RExp>> for &'a Fix<Bits, Base, LExp>
+        where
+            Fix<Bits, Base, LExp>: $imp<Fix<Bits, Base, RExp>, Output = Fix<Bits, Base, $out<LExp, RExp>>>,
+            LExp: $bound<RExp>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, $out<LExp, RExp>>;
+            fn $method(self, rhs: Fix<Bits, Base, RExp>) -> Self::Output {
+                $imp::$method(self.clone(), rhs)
+            }
+        }
+
+        impl<'a, Bits, Base, LExp, RExp> $imp<&'a Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
+        where
+            Fix<Bits, Base, LExp>: $imp<Fix<Bits, Base, RExp>, Output = Fix<Bits, Base, $out<LExp, RExp>>>,
+            LExp: $bound<RExp>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, $out<LExp, RExp>>;
+            fn $method(self, rhs: &'a Fix<Bits, Base, RExp>) -> Self::Output {
+                $imp::$method(self, rhs.clone())
+            }
+        }
+
+        impl<'a, 'b, Bits, Base, LExp, RExp> $imp<&'b Fix<Bits, Base, RExp>> for &'a Fix<Bits, Base, LExp>
+        where
+            Fix<Bits, Base, LExp>: $imp<Fix<Bits, Base, RExp>, Output = Fix<Bits, Base, $out<LExp, RExp>>>,
+            LExp: $bound<RExp>,
+            Bits: Clone,
+        {
+            type Output = Fix<Bits, Base, $out<LExp, RExp>>;
+            fn $method(self, rhs: &'b Fix<Bits, Base, RExp>) -> Self::Output {
+                $imp::$method(self.clone(), rhs.clone())
+            }
+        }
+    };
+}
+
+macro_rules! fixed_ref_op_assign {
+    ($imp:ident, $method:ident) => {
+        impl<'a, Bits, Base, Exp> $imp<&'a Fix<Bits, Base, Exp>> for Fix<Bits, Base, Exp>
+        where
+            Fix<Bits, Base, Exp>: $imp<Fix<Bits, Base, Exp>>,
+            Bits: Clone,
+        {
+            fn $method(&mut self, rhs: &'a Fix<Bits, Base, Exp>) {
+                $imp::$method(self, rhs.clone())
+            }
+        }
+    };
+}
+
+fixed_ref_unop!(Neg, neg);
+fixed_ref_binop!(Add, add);
+fixed_ref_binop!(Sub, sub);
+fixed_ref_binop!(Rem, rem);
+fixed_ref_scale_binop!(Mul, mul, Add, Sum);
+fixed_ref_scale_binop!(Div, div, Sub, Diff);
+
+fixed_ref_op_assign!(AddAssign, add_assign);
+fixed_ref_op_assign!(SubAssign, sub_assign);
+
+impl<'a, Bits, Base, LExp, RExp> RemAssign<&'a Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
+where
+    Bits: RemAssign + Clone,
+{
+    fn rem_assign(&mut self, rhs: &'a Fix<Bits, Base, RExp>) {
+        self.bits %= rhs.bits.clone();
+    }
+}
+
+// Float interop.
+
+/// Lossy conversion to a floating-point type.
+///
+/// Implemented generically for [`Fix`] so callers can be generic over the backing `Bits` type.
+pub trait ToFloat {
+    /// Converts `self` to an `f64`, scaling `bits` by `base.powi(exp)`.
+    fn to_f64(&self) -> f64;
+
+    /// Converts `self` to an `f32`. Defined in terms of [`to_f64`](ToFloat::to_f64).
+    fn to_f32(&self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+/// Conversion from a floating-point type, returning `None` when the rounded magnitude doesn't
+/// fit in the target `Bits`.
+pub trait FromFloat: Sized {
+    /// Converts `value` to `Self`, rounding `value / base.powi(exp)` to the nearest `Bits`.
+    fn from_f64(value: f64) -> Option<Self>;
+
+    /// Converts `value` to `Self`. Defined in terms of [`from_f64`](FromFloat::from_f64).
+    fn from_f32(value: f32) -> Option<Self> {
+        Self::from_f64(value as f64)
+    }
+}
+
+impl<Bits, Base, Exp> ToFloat for Fix<Bits, Base, Exp>
+where
+    Bits: ToPrimitive,
+    Base: Unsigned,
+    Exp: Integer,
+{
+    fn to_f64(&self) -> f64 {
+        let bits = self.bits.to_f64().expect("integer bits always fit in f64");
+        let base = Base::to_u64() as f64;
+        bits * FloatCore::powi(base, Exp::to_i32())
+    }
+}
+
+impl<Bits, Base, Exp> FromFloat for Fix<Bits, Base, Exp>
+where
+    Bits: FromPrimitive,
+    Base: Unsigned,
+    Exp: Integer,
+{
+    fn from_f64(value: f64) -> Option<Self> {
+        let base = Base::to_u64() as f64;
+        let scaled = value / FloatCore::powi(base, Exp::to_i32());
+        Bits::from_f64(FloatCore::round(scaled)).map(Fix::new)
+    }
+}
+
+// Decimal string conversions.
+
+/// Maximum number of decimal digits [`Display`] and [`FromStr`] will shift through. Generous
+/// enough for every integer primitive up to `u128`/`i128`.
+const MAX_DECIMAL_DIGITS: usize = 80;
+
+/// An error converting a string into a [`Fix`] via [`FromStr`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseFixError {
+    /// The string was empty (or just a sign/point with no digits).
+    Empty,
+    /// The string isn't a well-formed literal: it contains a character other than ASCII digits, a
+    /// leading `-` and a single `.`, or it has no digits before the `.`. For non-decimal bases,
+    /// returned when the string doesn't parse as an `f64`.
+    InvalidDigit,
+    /// The string has more fractional precision than `Exp` can represent exactly, or more than 80
+    /// digits. For non-decimal bases, also returned when the value doesn't fit in `Bits`.
+    TooPrecise,
+}
+
+impl<Bits, Base, Exp> Display for Fix<Bits, Base, Exp>
+where
+    Bits: FromUnsigned
+        + ToPrimitive
+        + PartialEq
+        + PartialOrd
+        + Default
+        + Clone
+        + Sub<Output = Bits>
+        + Div<Output = Bits>
+        + Rem<Output = Bits>,
+    Base: Unsigned,
+    Exp: Integer,
+    Self: ToFloat,
+{
+    /// Renders `self` as a decimal string.
+    ///
+    /// For base-10 `Fix`es, this inserts a radix point `Exp` digits from the right (zero-padding
+    /// as needed), so the full precision of the type is always shown. Non-decimal bases (e.g.
+    /// [`aliases::iec`](crate::aliases::iec) types) fall back to an approximate `f64` rendering,
+    /// since their scale isn't a power of ten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::si::Milli;
+    /// assert_eq!("1.500", Milli::new(1_500).to_string());
+    /// assert_eq!("-1.500", Milli::new(-1_500).to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if Base::to_u64() != 10 {
+            return write!(f, "{}", self.to_f64());
+        }
+
+        let zero = Bits::default();
+        let ten = Bits::from_unsigned::<typenum::consts::U10>();
+        let negative = self.bits < zero;
+        let mut mag = if negative {
+            zero.clone() - self.bits.clone()
+        } else {
+            self.bits.clone()
+        };
+
+        let mut digits = [0u8; MAX_DECIMAL_DIGITS];
+        let mut n = 0;
+        while mag != zero && n < digits.len() {
+            digits[n] = (mag.clone() % ten.clone()).to_u8().unwrap_or(0);
+            mag = mag / ten.clone();
+            n += 1;
+        }
+        if n == 0 {
+            digits[0] = 0;
+            n = 1;
+        }
+
+        if negative {
+            write!(f, "-")?;
+        }
+
+        let exp = Exp::to_i32();
+        let is_zero = n == 1 && digits[0] == 0;
+        if exp >= 0 {
+            for &d in digits[..n].iter().rev() {
+                write!(f, "{}", d)?;
+            }
+            if !is_zero {
+                for _ in 0..exp {
+                    write!(f, "0")?;
+                }
+            }
+        } else {
+            let frac_digits = (-exp) as usize;
+            if n <= frac_digits {
+                write!(f, "0.")?;
+                for _ in 0..(frac_digits - n) {
+                    write!(f, "0")?;
+                }
+                for &d in digits[..n].iter().rev() {
+                    write!(f, "{}", d)?;
+                }
+            } else {
+                for &d in digits[frac_digits..n].iter().rev() {
+                    write!(f, "{}", d)?;
+                }
+                write!(f, ".")?;
+                for &d in digits[..frac_digits].iter().rev() {
+                    write!(f, "{}", d)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Bits, Base, Exp> core::str::FromStr for Fix<Bits, Base, Exp>
+where
+    Bits: FromUnsigned
+        + FromPrimitive
+        + Default
+        + Clone
+        + Sub<Output = Bits>
+        + Mul<Output = Bits>
+        + Add<Output = Bits>,
+    Base: Unsigned,
+    Exp: Integer,
+    Self: FromFloat,
+{
+    type Err = ParseFixError;
+
+    /// Parses a decimal literal, such as `"1.5"` or `"-42"`, into a `Fix<Bits, Base, Exp>`.
+    ///
+    /// For non-decimal bases, the literal is parsed as an `f64` and converted via
+    /// [`FromFloat::from_f64`]. For base-10 types, parsing is exact: it errors with
+    /// [`ParseFixError::TooPrecise`] rather than silently rounding away digits `Exp` can't
+    /// represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::si::Milli;
+    /// assert_eq!(Ok(Milli::new(1_500)), "1.5".parse());
+    /// assert!("1.5001".parse::<Milli<i32>>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseFixError::Empty);
+        }
+
+        if Base::to_u64() != 10 {
+            let value: f64 = s.parse().map_err(|_| ParseFixError::InvalidDigit)?;
+            return Self::from_f64(value).ok_or(ParseFixError::TooPrecise);
+        }
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let (int_part, frac_part) = match unsigned.find('.') {
+            Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+            None => (unsigned, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(ParseFixError::Empty);
+        }
+
+        if int_part.is_empty()
+            || !int_part.bytes().all(|b| b.is_ascii_digit())
+            || !frac_part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseFixError::InvalidDigit);
+        }
+
+        let mut combined = [0u8; MAX_DECIMAL_DIGITS];
+        let mut len = 0;
+        for b in int_part.bytes().chain(frac_part.bytes()) {
+            if len >= combined.len() {
+                return Err(ParseFixError::TooPrecise);
+            }
+            combined[len] = b - b'0';
+            len += 1;
+        }
+
+        let exp = Exp::to_i32();
+        let shift = frac_part.len() as i32 + exp;
+
+        let keep = if shift > 0 {
+            // Digits missing on the left count as zeros, so e.g. "0" still parses at a positive
+            // `Exp`.
+            let drop = (shift as usize).min(len);
+            if combined[len - drop..len].iter().any(|&d| d != 0) {
+                return Err(ParseFixError::TooPrecise);
+            }
+            len - drop
+        } else {
+            len
+        };
+
+        let zero = Bits::default();
+        let ten = Bits::from_unsigned::<typenum::consts::U10>();
+        let mut bits = zero.clone();
+        for &d in &combined[..keep] {
+            let digit = Bits::from_u8(d).ok_or(ParseFixError::TooPrecise)?;
+            bits = bits * ten.clone() + digit;
+        }
+
+        if shift < 0 {
+            for _ in 0..(-shift) {
+                bits = bits * ten.clone();
+            }
+        }
+
+        if negative {
+            bits = zero - bits;
+        }
+
+        Ok(Self::new(bits))
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::op_ref)] // the point of these tests is exercising the by-reference operator impls
 mod tests {
     use crate::aliases::si::{Kilo, Milli, Unit};
-    use crate::{CheckedAdd, CheckedDivFix, CheckedMulFix, CheckedSub};
+    use crate::num_traits::{Bounded, Num, One, SaturatingAdd, SaturatingSub, Signed, Zero};
+    use crate::{
+        CheckedAdd, CheckedDivFix, CheckedMulFix, CheckedSub, FromFloat, ParseFixError,
+        RoundingMode, SaturatingDivFix, SaturatingMulFix, ToFloat,
+    };
+    use std::string::ToString;
 
     #[test]
     fn convert_milli_to_kilo() {
@@ -812,4 +1515,314 @@ mod tests {
         let mapped = one.map_bits(|b| b as u8);
         assert_eq!(mapped, Milli::new(163u8));
     }
+
+    #[test]
+    fn neg_ref() {
+        assert_eq!(Kilo::new(-1), -&Kilo::new(1i32));
+    }
+
+    #[test]
+    fn add_ref() {
+        let a = Kilo::new(1);
+        let b = Kilo::new(2);
+        assert_eq!(Kilo::new(3), &a + b);
+        assert_eq!(Kilo::new(3), a + &b);
+        assert_eq!(Kilo::new(3), &a + &b);
+    }
+
+    #[test]
+    fn sub_ref() {
+        let a = Kilo::new(3);
+        let b = Kilo::new(2);
+        assert_eq!(Kilo::new(1), &a - b);
+        assert_eq!(Kilo::new(1), a - &b);
+        assert_eq!(Kilo::new(1), &a - &b);
+    }
+
+    #[test]
+    fn mul_ref() {
+        let a = Kilo::new(2);
+        let b = Milli::new(3);
+        assert_eq!(Unit::new(6), &a * b);
+        assert_eq!(Unit::new(6), a * &b);
+        assert_eq!(Unit::new(6), &a * &b);
+    }
+
+    #[test]
+    fn div_ref() {
+        let a = Kilo::new(6);
+        let b = Kilo::new(2);
+        assert_eq!(Unit::new(3), &a / b);
+        assert_eq!(Unit::new(3), a / &b);
+        assert_eq!(Unit::new(3), &a / &b);
+    }
+
+    #[test]
+    fn rem_ref() {
+        let a = Kilo::new(6);
+        let b = Kilo::new(5);
+        assert_eq!(Kilo::new(1), &a % b);
+        assert_eq!(Kilo::new(1), a % &b);
+        assert_eq!(Kilo::new(1), &a % &b);
+    }
+
+    #[test]
+    fn add_assign_ref() {
+        let mut a = Kilo::new(1);
+        a += &Kilo::new(2);
+        assert_eq!(Kilo::new(3), a);
+    }
+
+    #[test]
+    fn sub_assign_ref() {
+        let mut a = Kilo::new(3);
+        a -= &Kilo::new(2);
+        assert_eq!(Kilo::new(1), a);
+    }
+
+    #[test]
+    fn rem_assign_ref() {
+        let mut a = Kilo::new(6);
+        a %= &Milli::new(5);
+        assert_eq!(Kilo::new(1), a);
+    }
+
+    #[test]
+    fn convert_round_truncate() {
+        let milli = Milli::new(1_999);
+        assert_eq!(Unit::new(1), milli.convert_round(RoundingMode::Truncate));
+        assert_eq!(Unit::new(1), milli.convert());
+    }
+
+    #[test]
+    fn convert_round_floor() {
+        assert_eq!(Unit::new(1), Milli::new(1_999).convert_round(RoundingMode::Floor));
+        assert_eq!(Unit::new(-2), Milli::new(-1_999).convert_round(RoundingMode::Floor));
+    }
+
+    #[test]
+    fn convert_round_ceil() {
+        assert_eq!(Unit::new(2), Milli::new(1_999).convert_round(RoundingMode::Ceil));
+        assert_eq!(Unit::new(-1), Milli::new(-1_999).convert_round(RoundingMode::Ceil));
+    }
+
+    #[test]
+    fn convert_round_half_up() {
+        assert_eq!(Unit::new(2), Milli::new(1_500).convert_round(RoundingMode::HalfUp));
+        assert_eq!(Unit::new(1), Milli::new(1_499).convert_round(RoundingMode::HalfUp));
+        assert_eq!(Unit::new(-2), Milli::new(-1_500).convert_round(RoundingMode::HalfUp));
+    }
+
+    #[test]
+    fn convert_round_half_to_even() {
+        assert_eq!(Unit::new(2), Milli::new(2_500).convert_round(RoundingMode::HalfToEven));
+        assert_eq!(Unit::new(2), Milli::new(1_500).convert_round(RoundingMode::HalfToEven));
+        assert_eq!(Unit::new(1), Milli::new(1_499).convert_round(RoundingMode::HalfToEven));
+    }
+
+    #[test]
+    fn convert_round_exact_no_rounding() {
+        assert_eq!(Unit::new(2), Milli::new(2_000).convert_round(RoundingMode::Floor));
+    }
+
+    #[test]
+    fn to_f64_basic() {
+        assert!((1.5 - Milli::new(1_500).to_f64()).abs() < 1e-9);
+        assert!((-1.5 - Milli::new(-1_500).to_f64()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_f64_basic() {
+        assert_eq!(Some(Milli::new(1_500)), Milli::from_f64(1.5));
+    }
+
+    #[test]
+    fn from_f64_rounds() {
+        assert_eq!(Some(Milli::new(1_500)), Milli::from_f64(1.4999999));
+    }
+
+    #[test]
+    fn from_f64_out_of_range() {
+        assert_eq!(None, Kilo::<i8>::from_f64(1_000_000.0));
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Kilo::new(0), Kilo::<i32>::zero());
+        assert!(Kilo::<i32>::zero().is_zero());
+        assert!(!Kilo::new(1).is_zero());
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(Unit::new(1), Unit::<i32>::one());
+    }
+
+    #[test]
+    fn bounded() {
+        assert_eq!(Kilo::new(i8::MIN), Kilo::<i8>::min_value());
+        assert_eq!(Kilo::new(i8::MAX), Kilo::<i8>::max_value());
+    }
+
+    #[test]
+    fn num_from_str_radix() {
+        assert_eq!(Unit::new(42), Unit::<i32>::from_str_radix("42", 10).unwrap());
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(Unit::new(5), Unit::new(-5i32).abs());
+        assert_eq!(Unit::new(1), Unit::new(5i32).signum());
+        assert_eq!(Unit::new(-1), Unit::new(-5i32).signum());
+        assert!(Unit::new(5i32).is_positive());
+        assert!(Unit::new(-5i32).is_negative());
+    }
+
+    #[test]
+    fn display_positive() {
+        assert_eq!("1.500", Milli::new(1_500).to_string());
+    }
+
+    #[test]
+    fn display_negative() {
+        assert_eq!("-1.500", Milli::new(-1_500).to_string());
+    }
+
+    #[test]
+    fn display_zero_padded() {
+        assert_eq!("0.005", Milli::new(5).to_string());
+    }
+
+    #[test]
+    fn display_integer_exp() {
+        assert_eq!("5000", Kilo::new(5).to_string());
+    }
+
+    #[test]
+    fn display_zero_integer_exp() {
+        assert_eq!("0", Kilo::new(0).to_string());
+        assert_eq!("0", Unit::new(0).to_string());
+        assert_eq!("0.000", Milli::new(0).to_string());
+    }
+
+    #[test]
+    fn from_str_exact() {
+        let parsed: Milli<i32> = "1.5".parse().unwrap();
+        assert_eq!(Milli::new(1_500), parsed);
+    }
+
+    #[test]
+    fn from_str_negative() {
+        let parsed: Milli<i32> = "-1.5".parse().unwrap();
+        assert_eq!(Milli::new(-1_500), parsed);
+    }
+
+    #[test]
+    fn from_str_too_precise() {
+        assert_eq!(Err(ParseFixError::TooPrecise), "1.5001".parse::<Milli<i32>>());
+    }
+
+    #[test]
+    fn from_str_invalid_digit() {
+        assert_eq!(Err(ParseFixError::InvalidDigit), "1.5x".parse::<Milli<i32>>());
+    }
+
+    #[test]
+    fn from_str_empty() {
+        assert_eq!(Err(ParseFixError::Empty), "".parse::<Milli<i32>>());
+        assert_eq!(Err(ParseFixError::Empty), "-".parse::<Milli<i32>>());
+        assert_eq!(Err(ParseFixError::Empty), ".".parse::<Milli<i32>>());
+    }
+
+    #[test]
+    fn from_str_no_fraction() {
+        let parsed: Kilo<i32> = "5000".parse().unwrap();
+        assert_eq!(Kilo::new(5), parsed);
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        let value = Milli::new(-42_123);
+        let parsed: Milli<i32> = value.to_string().parse().unwrap();
+        assert_eq!(value, parsed);
+    }
+
+    #[test]
+    fn saturating_add_no_overflow() {
+        let forty = Kilo::new(40i8);
+        let two = Kilo::new(2i8);
+        assert_eq!(Kilo::new(42), forty.saturating_add(&two));
+    }
+
+    #[test]
+    fn saturating_add_overflow_max() {
+        let max = Kilo::new(i8::MAX);
+        let one = Kilo::new(1i8);
+        assert_eq!(Kilo::new(i8::MAX), max.saturating_add(&one));
+    }
+
+    #[test]
+    fn saturating_add_overflow_min() {
+        let min = Kilo::new(i8::MIN);
+        let one = Kilo::new(-1i8);
+        assert_eq!(Kilo::new(i8::MIN), min.saturating_add(&one));
+    }
+
+    #[test]
+    fn saturating_sub_no_overflow() {
+        let fifty = Kilo::new(50i8);
+        let eight = Kilo::new(8i8);
+        assert_eq!(Kilo::new(42), fifty.saturating_sub(&eight));
+    }
+
+    #[test]
+    fn saturating_sub_overflow_max() {
+        let max = Kilo::new(i8::MAX);
+        let neg_one = Kilo::new(-1i8);
+        assert_eq!(Kilo::new(i8::MAX), max.saturating_sub(&neg_one));
+    }
+
+    #[test]
+    fn saturating_sub_overflow_min() {
+        let min = Kilo::new(i8::MIN);
+        let one = Kilo::new(1i8);
+        assert_eq!(Kilo::new(i8::MIN), min.saturating_sub(&one));
+    }
+
+    #[test]
+    fn saturating_mul_no_overflow() {
+        let fifty = Kilo::new(50_i64);
+        assert_eq!(
+            fifty.saturating_mul(&fifty).convert(),
+            Kilo::new(2_500_000_i64)
+        );
+    }
+
+    #[test]
+    fn saturating_mul_overflow_max() {
+        let fifty = Unit::new(50i8);
+        let max = Unit::new(i8::MAX);
+        assert_eq!(Unit::new(i8::MAX), fifty.saturating_mul(&max));
+    }
+
+    #[test]
+    fn saturating_mul_overflow_min() {
+        let fifty = Unit::new(50i8);
+        let min = Unit::new(i8::MIN);
+        assert_eq!(Unit::new(i8::MIN), fifty.saturating_mul(&min));
+    }
+
+    #[test]
+    fn saturating_div_no_overflow() {
+        let hundred = Kilo::new(100i8);
+        let five = Kilo::new(5i8);
+        assert_eq!(Unit::new(20), hundred.saturating_div(&five));
+    }
+
+    #[test]
+    fn saturating_div_overflow_max() {
+        let min = Unit::new(i8::MIN);
+        let neg_one = Unit::new(-1i8);
+        assert_eq!(Unit::new(i8::MAX), min.saturating_div(&neg_one));
+    }
 }
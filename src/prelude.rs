@@ -1,6 +1,7 @@
 pub use crate::Fix;
-pub use crate::{CheckedMulFix, CheckedDivFix};
+pub use crate::{CheckedMulFix, CheckedDivFix, SaturatingMulFix, SaturatingDivFix};
 pub use crate::num_traits::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv};
+pub use crate::num_traits::{SaturatingAdd, SaturatingSub};
 pub use crate::muldiv::MulDiv;
 #[allow(unused)]
 #[cfg(feature = "anchor")]